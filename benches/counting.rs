@@ -0,0 +1,21 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use kincaid::Kincaid;
+
+const SAMPLE: &str = include_str!("sample.txt");
+
+fn bench_word_count(c: &mut Criterion) {
+    let kincaid = Kincaid::new();
+    c.bench_function("word_count", |b| {
+        b.iter(|| kincaid.word_count(black_box(SAMPLE)))
+    });
+}
+
+fn bench_sentence_count(c: &mut Criterion) {
+    let kincaid = Kincaid::new();
+    c.bench_function("sentence_count", |b| {
+        b.iter(|| kincaid.sentence_count(black_box(SAMPLE)))
+    });
+}
+
+criterion_group!(benches, bench_word_count, bench_sentence_count);
+criterion_main!(benches);
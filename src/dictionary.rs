@@ -0,0 +1,107 @@
+//! Dictionary-backed syllable lookups.
+//!
+//! Parses a CMU Pronouncing Dictionary style file where each line is
+//! `WORD  PH0 PH1 PH2 ...`. Stress digits (`0`, `1`, `2`) mark vowel
+//! phonemes, so the syllable count for a word is just the number of
+//! stress-marked phonemes in its entry.
+
+#[cfg(feature = "std")]
+use std::collections::BTreeMap;
+#[cfg(not(feature = "std"))]
+use alloc::collections::BTreeMap;
+
+#[cfg(feature = "std")]
+use std::string::String;
+#[cfg(not(feature = "std"))]
+use alloc::string::String;
+
+#[cfg(feature = "std")]
+use std::io::{self, BufRead};
+
+// A small built-in word list covering common everyday words, in the
+// same format `parse` understands. It gives `Kincaid::new()` useful
+// dictionary-backed syllable counts out of the box; load a full CMU
+// dict through `Kincaid::with_dictionary` for broader coverage.
+static DEFAULT_DICTIONARY: &str = include_str!("cmudict_default.txt");
+
+pub(crate) fn default() -> BTreeMap<String, u8> {
+    let mut words = BTreeMap::new();
+    for line in DEFAULT_DICTIONARY.lines() {
+        parse_line(line, &mut words);
+    }
+    words
+}
+
+#[cfg(feature = "std")]
+pub(crate) fn parse(reader: impl BufRead) -> io::Result<BTreeMap<String, u8>> {
+    let mut words = BTreeMap::new();
+
+    for line in reader.lines() {
+        parse_line(line?.trim(), &mut words);
+    }
+
+    Ok(words)
+}
+
+fn parse_line(line: &str, words: &mut BTreeMap<String, u8>) {
+    let line = line.trim();
+
+    if line.is_empty() || line.starts_with(";;;") {
+        return;
+    }
+
+    if let Some((word, phonemes)) = line.split_once(char::is_whitespace) {
+        let word = strip_variant_marker(word).to_lowercase();
+        let syllables = phonemes
+            .split_whitespace()
+            .filter(|phoneme| ends_with_stress_digit(phoneme))
+            .count() as u8;
+
+        words.insert(word, syllables);
+    }
+}
+
+fn ends_with_stress_digit(phoneme: &str) -> bool {
+    matches!(phoneme.as_bytes().last(), Some(b'0' | b'1' | b'2'))
+}
+
+// CMU dict lists alternate pronunciations as `WORD(1)`, `WORD(2)`, etc.
+fn strip_variant_marker(word: &str) -> &str {
+    match word.find('(') {
+        Some(index) => &word[..index],
+        None => word,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_parse() {
+        let dict = "HELLO  HH AH0 L OW1\nWORLD  W ER1 L D\n";
+        let words = parse(dict.as_bytes()).unwrap();
+
+        assert_eq!(words.get("hello"), Some(&2));
+        assert_eq!(words.get("world"), Some(&1));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_parse_ignores_comments_and_variants() {
+        let dict = ";;; comment\nREAD(1)  R IY1 D\n";
+        let words = parse(dict.as_bytes()).unwrap();
+
+        assert_eq!(words.get("read"), Some(&1));
+        assert_eq!(words.len(), 1);
+    }
+
+    #[test]
+    fn test_default_is_well_formed() {
+        let words = default();
+
+        assert_eq!(words.get("hello"), Some(&2));
+        assert!(!words.is_empty());
+    }
+}
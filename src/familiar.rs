@@ -0,0 +1,74 @@
+//! Familiar-word list loading, for the Dale–Chall formula.
+//!
+//! The expected format is one word per line, such as the ~3000-word
+//! Dale–Chall list of words familiar to most fourth-graders.
+
+#[cfg(feature = "std")]
+use std::collections::BTreeSet;
+#[cfg(not(feature = "std"))]
+use alloc::collections::BTreeSet;
+
+#[cfg(feature = "std")]
+use std::string::String;
+#[cfg(not(feature = "std"))]
+use alloc::string::String;
+
+#[cfg(feature = "std")]
+use std::io::{self, BufRead};
+
+// A small curated subset of words familiar to most fourth-graders, in
+// the same one-word-per-line format `parse` understands. It gives
+// `Kincaid::new()` a usable Dale–Chall baseline out of the box; load a
+// fuller list through `Kincaid::with_familiar_words` for accuracy
+// closer to the real ~3000-word Dale–Chall list.
+static DEFAULT_FAMILIAR_WORDS: &str = include_str!("familiar_words_default.txt");
+
+pub(crate) fn default() -> BTreeSet<String> {
+    let mut words = BTreeSet::new();
+    for line in DEFAULT_FAMILIAR_WORDS.lines() {
+        parse_line(line, &mut words);
+    }
+    words
+}
+
+#[cfg(feature = "std")]
+pub(crate) fn parse(reader: impl BufRead) -> io::Result<BTreeSet<String>> {
+    let mut words = BTreeSet::new();
+
+    for line in reader.lines() {
+        parse_line(&line?, &mut words);
+    }
+
+    Ok(words)
+}
+
+fn parse_line(line: &str, words: &mut BTreeSet<String>) {
+    let word = line.trim();
+
+    if !word.is_empty() && !word.starts_with(";;;") {
+        words.insert(word.to_lowercase());
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_parse() {
+        let list = "the\nA\n\nand\n";
+        let words = parse(list.as_bytes()).unwrap();
+
+        assert_eq!(words.len(), 3);
+        assert!(words.contains("a"));
+    }
+
+    #[test]
+    fn test_default_is_well_formed() {
+        let words = default();
+
+        assert!(words.contains("the"));
+        assert!(!words.is_empty());
+    }
+}
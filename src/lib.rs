@@ -1,11 +1,88 @@
-use std::{cmp, fmt::Display, hint::unreachable_unchecked};
-
+//! `kincaid` works without the standard library when built with
+//! `default-features = false`, so long as an allocator is available.
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(not(feature = "std"))]
+extern crate alloc;
+
+#[cfg(feature = "std")]
+use std::{
+    cmp,
+    collections::BTreeSet,
+    fmt::{self, Display},
+    hint::unreachable_unchecked,
+};
+#[cfg(not(feature = "std"))]
+use core::{
+    cmp,
+    fmt::{self, Display},
+    hint::unreachable_unchecked,
+};
+#[cfg(not(feature = "std"))]
+use alloc::{collections::BTreeSet, format, string::String};
+
+#[cfg(feature = "dictionary")]
+#[cfg(feature = "std")]
+use std::collections::BTreeMap;
+#[cfg(feature = "dictionary")]
+#[cfg(not(feature = "std"))]
+use alloc::collections::BTreeMap;
+
+#[cfg(all(any(feature = "dictionary", feature = "dale-chall"), feature = "std"))]
+use std::io;
+
+use memchr::memchr3_iter;
 use regex::{Regex, RegexBuilder, RegexSet, RegexSetBuilder};
 
+#[cfg(feature = "dale-chall")]
+mod familiar;
+#[cfg(feature = "dictionary")]
+mod dictionary;
+mod stem;
+mod style;
+
+pub use style::{Lint, LintCategory, StyleLinter};
+
 static WORD_PATTERN: &str = r"\b(\p{L}+(?:[-']\p{L}+)?)\b";
 static SENTENCE_PATTERN: &str = r"[.?!]+";
 static VOWEL_GROUP_PATTERN: &str = r"[aeiou]+";
 
+static DEFAULT_STOPWORD_PATTERNS: &[&str] = &[
+    r"\ba\b",
+    r"\ban\b",
+    r"\bthe\b",
+    r"\band\b",
+    r"\bbut\b",
+    r"\bor\b",
+    r"\bof\b",
+    r"\bto\b",
+    r"\bin\b",
+    r"\bon\b",
+    r"\bat\b",
+    r"\bfor\b",
+    r"\bwith\b",
+    r"\bas\b",
+    r"\bby\b",
+    r"\bis\b",
+    r"\bare\b",
+    r"\bwas\b",
+    r"\bwere\b",
+    r"\bbe\b",
+    r"\bbeen\b",
+    r"\bbeing\b",
+    r"\bit\b",
+    r"\bthis\b",
+    r"\bthat\b",
+    r"\bthese\b",
+    r"\bthose\b",
+    r"\bhe\b",
+    r"\bshe\b",
+    r"\bthey\b",
+    r"\bwe\b",
+    r"\byou\b",
+    r"\bi\b",
+];
+
 #[derive(Clone, Debug)]
 pub struct Kincaid {
     word: Regex,
@@ -13,6 +90,11 @@ pub struct Kincaid {
     vowel_group: Regex,
     add: RegexSet,
     sub: RegexSet,
+    stopwords: RegexSet,
+    #[cfg(feature = "dictionary")]
+    dictionary: Option<BTreeMap<String, u8>>,
+    #[cfg(feature = "dale-chall")]
+    familiar_words: Option<BTreeSet<String>>,
 }
 
 impl Kincaid {
@@ -236,15 +318,68 @@ impl Kincaid {
             vowel_group: build(VOWEL_GROUP_PATTERN),
             add: build_set(ADD_PATTERNS),
             sub: build_set(SUB_PATTERNS),
+            stopwords: build_set(DEFAULT_STOPWORD_PATTERNS),
+            #[cfg(feature = "dictionary")]
+            dictionary: Some(dictionary::default()),
+            #[cfg(feature = "dale-chall")]
+            familiar_words: Some(familiar::default()),
         }
     }
 
+    /// Build a `Kincaid` with a custom stopword list for lexical
+    /// diversity analysis.
+    ///
+    /// `patterns` replaces the default English stopword set used by
+    /// [`Scorer::lexical_diversity`] and [`Scorer::unique_word_count`]
+    /// to exclude function words from content-word counts.
+    pub fn with_stopwords(patterns: &[&str]) -> Self {
+        let mut kincaid = Self::new();
+        kincaid.stopwords = build_set(patterns);
+        kincaid
+    }
+
+    /// Build a `Kincaid` backed by a pronunciation dictionary.
+    ///
+    /// `reader` should yield a CMU Pronouncing Dictionary style file
+    /// (`WORD  PH0 PH1 ...`, one entry per line). Words found in the
+    /// dictionary are scored by counting stress-marked phonemes;
+    /// out-of-vocabulary words still fall back to the vowel-group
+    /// heuristic. This replaces the small built-in word list that
+    /// [`Kincaid::new`] already loads.
+    #[cfg(all(feature = "dictionary", feature = "std"))]
+    pub fn with_dictionary(reader: impl io::BufRead) -> io::Result<Self> {
+        let mut kincaid = Self::new();
+        kincaid.dictionary = Some(dictionary::parse(reader)?);
+        Ok(kincaid)
+    }
+
+    /// Build a `Kincaid` with a familiar-word list for Dale–Chall scoring.
+    ///
+    /// `reader` should yield one word per line. Words absent from this
+    /// list count as "difficult" when [`Scorer::dale_chall`] is used.
+    /// This replaces the small built-in word list that [`Kincaid::new`]
+    /// already loads.
+    #[cfg(all(feature = "dale-chall", feature = "std"))]
+    pub fn with_familiar_words(reader: impl io::BufRead) -> io::Result<Self> {
+        let mut kincaid = Self::new();
+        kincaid.familiar_words = Some(familiar::parse(reader)?);
+        Ok(kincaid)
+    }
+
     pub fn word_count(&self, text: &str) -> usize {
-        self.word.find_iter(text).count()
+        if text.is_ascii() {
+            ascii_word_count(text.as_bytes())
+        } else {
+            self.word.find_iter(text).count()
+        }
     }
 
     pub fn sentence_count(&self, text: &str) -> usize {
-        cmp::max(1, self.sentence.find_iter(text).count())
+        if text.is_ascii() {
+            cmp::max(1, ascii_sentence_count(text.as_bytes()))
+        } else {
+            cmp::max(1, self.sentence.find_iter(text).count())
+        }
     }
 
     pub fn syllable_count(&self, text: &str) -> usize {
@@ -254,7 +389,7 @@ impl Kincaid {
             .sum()
     }
 
-    pub fn scorer(&self) -> Scorer {
+    pub fn scorer(&self) -> Scorer<'_> {
         Scorer::new(self)
     }
 
@@ -271,6 +406,13 @@ impl Kincaid {
     }
 
     fn syllables_in_word(&self, text: &str) -> usize {
+        #[cfg(feature = "dictionary")]
+        if let Some(dictionary) = &self.dictionary {
+            if let Some(&syllables) = dictionary.get(&text.to_lowercase()) {
+                return syllables as usize;
+            }
+        }
+
         let count = self.vowel_group.find_iter(text).count();
         let add = self.add.matches(text).iter().count();
         let sub = self.sub.matches(text).iter().count();
@@ -296,6 +438,16 @@ pub struct Scorer<'a> {
     words: usize,
     syllables: usize,
     sentences: usize,
+    // Letters only, for Coleman-Liau's "letters per 100 words". The
+    // Automated Readability Index is usually defined over all
+    // characters (letters, digits, and punctuation, excluding spaces),
+    // so `automated_readability_index` undercounts slightly relative
+    // to reference implementations on text with lots of digits/punctuation.
+    chars: usize,
+    complex_words: usize,
+    #[cfg(feature = "dale-chall")]
+    difficult_words: usize,
+    content_stems: BTreeSet<String>,
 }
 
 impl<'a> Scorer<'a> {
@@ -305,6 +457,11 @@ impl<'a> Scorer<'a> {
             words: 0,
             syllables: 0,
             sentences: 0,
+            chars: 0,
+            complex_words: 0,
+            #[cfg(feature = "dale-chall")]
+            difficult_words: 0,
+            content_stems: BTreeSet::new(),
         }
     }
 
@@ -313,6 +470,91 @@ impl<'a> Scorer<'a> {
         self.words += self.kincaid.word_count(text);
         self.syllables += self.kincaid.syllable_count(text);
         self.sentences += self.kincaid.sentence_count(text);
+        self.chars += text.chars().filter(|c| c.is_alphabetic()).count();
+
+        for m in self.kincaid.word.find_iter(text) {
+            let word = m.as_str();
+
+            if self.kincaid.syllables_in_word(word) >= 3 {
+                self.complex_words += 1;
+            }
+
+            #[cfg(feature = "dale-chall")]
+            if let Some(familiar_words) = &self.kincaid.familiar_words {
+                let lower = word.to_lowercase();
+                let familiar = familiar_words.contains(&lower)
+                    || familiar_words.contains(&stem::stem(&lower));
+
+                if !familiar {
+                    self.difficult_words += 1;
+                }
+            }
+
+            if !self.kincaid.stopwords.is_match(word) {
+                self.content_stems.insert(stem::stem(word));
+            }
+        }
+    }
+
+    /// The ratio of unique content-word stems (stopwords excluded) to
+    /// total words seen so far.
+    pub fn lexical_diversity(&self) -> f64 {
+        self.content_stems.len() as f64 / self.words as f64
+    }
+
+    /// The number of distinct content words (stopwords excluded) seen
+    /// so far, after collapsing inflected forms to a common stem.
+    pub fn unique_word_count(&self) -> usize {
+        self.content_stems.len()
+    }
+
+    /// Calculate the Gunning Fog index.
+    pub fn gunning_fog(&self) -> GunningFog {
+        GunningFog(
+            0.4 * ((self.words as f64 / self.sentences as f64)
+                + 100.0 * (self.complex_words as f64 / self.words as f64)),
+        )
+    }
+
+    /// Calculate the SMOG grade.
+    pub fn smog(&self) -> Smog {
+        Smog(1.0430 * sqrt(self.complex_words as f64 * (30.0 / self.sentences as f64)) + 3.1291)
+    }
+
+    /// Calculate the Automated Readability Index.
+    pub fn automated_readability_index(&self) -> AutomatedReadabilityIndex {
+        AutomatedReadabilityIndex(
+            4.71 * (self.chars as f64 / self.words as f64)
+                + 0.5 * (self.words as f64 / self.sentences as f64)
+                - 21.43,
+        )
+    }
+
+    /// Calculate the Coleman–Liau index.
+    pub fn coleman_liau(&self) -> ColemanLiau {
+        let letters_per_100_words = (self.chars as f64 / self.words as f64) * 100.0;
+        let sentences_per_100_words = (self.sentences as f64 / self.words as f64) * 100.0;
+
+        ColemanLiau(0.0588 * letters_per_100_words - 0.296 * sentences_per_100_words - 15.8)
+    }
+
+    /// Calculate the Dale–Chall readability score.
+    ///
+    /// `Kincaid::new` loads a small built-in familiar-word list, so this
+    /// returns `Some` by default; swap in a fuller list with
+    /// [`Kincaid::with_familiar_words`] for more accurate results.
+    #[cfg(feature = "dale-chall")]
+    pub fn dale_chall(&self) -> Option<DaleChall> {
+        self.kincaid.familiar_words.as_ref()?;
+
+        let difficult_percent = self.difficult_words as f64 / self.words as f64 * 100.0;
+        let raw = 0.1579 * difficult_percent + 0.0496 * (self.words as f64 / self.sentences as f64);
+
+        Some(DaleChall(if difficult_percent > 5.0 {
+            raw + 3.6365
+        } else {
+            raw
+        }))
     }
 
     /// Calculate grade level.
@@ -407,7 +649,7 @@ impl ReadingEase {
 }
 
 impl Display for ReadingEase {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{:.01}", self.0)
     }
 }
@@ -416,16 +658,115 @@ pub struct GradeLevel(f64);
 
 impl GradeLevel {
     pub fn description(&self) -> String {
-        match self.0.trunc() as i32 {
-            1 => String::from("1st grade"),
-            2 => String::from("2nd grade"),
-            3 => String::from("3rd grade"),
-            n => format!("{}th grade", n),
-        }
+        describe_grade(self.0)
     }
 }
 
-fn build(pattern: &str) -> Regex {
+pub struct GunningFog(f64);
+
+impl GunningFog {
+    pub fn description(&self) -> String {
+        describe_grade(self.0)
+    }
+}
+
+impl Display for GunningFog {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:.01}", self.0)
+    }
+}
+
+pub struct Smog(f64);
+
+impl Smog {
+    pub fn description(&self) -> String {
+        describe_grade(self.0)
+    }
+}
+
+impl Display for Smog {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:.01}", self.0)
+    }
+}
+
+pub struct AutomatedReadabilityIndex(f64);
+
+impl AutomatedReadabilityIndex {
+    pub fn description(&self) -> String {
+        describe_grade(self.0)
+    }
+}
+
+impl Display for AutomatedReadabilityIndex {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:.01}", self.0)
+    }
+}
+
+pub struct ColemanLiau(f64);
+
+impl ColemanLiau {
+    pub fn description(&self) -> String {
+        describe_grade(self.0)
+    }
+}
+
+impl Display for ColemanLiau {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:.01}", self.0)
+    }
+}
+
+#[cfg(feature = "dale-chall")]
+pub struct DaleChall(f64);
+
+#[cfg(feature = "dale-chall")]
+impl DaleChall {
+    pub fn description(&self) -> String {
+        describe_grade(self.0)
+    }
+}
+
+#[cfg(feature = "dale-chall")]
+impl Display for DaleChall {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:.01}", self.0)
+    }
+}
+
+fn describe_grade(grade: f64) -> String {
+    match trunc(grade) as i32 {
+        1 => String::from("1st grade"),
+        2 => String::from("2nd grade"),
+        3 => String::from("3rd grade"),
+        n => format!("{}th grade", n),
+    }
+}
+
+// `f64::sqrt`/`f64::trunc` require `std` (they call into the system's
+// libm); fall back to `libm` for `no_std` builds.
+#[cfg(feature = "std")]
+fn sqrt(x: f64) -> f64 {
+    x.sqrt()
+}
+
+#[cfg(not(feature = "std"))]
+fn sqrt(x: f64) -> f64 {
+    libm::sqrt(x)
+}
+
+#[cfg(feature = "std")]
+fn trunc(x: f64) -> f64 {
+    x.trunc()
+}
+
+#[cfg(not(feature = "std"))]
+fn trunc(x: f64) -> f64 {
+    libm::trunc(x)
+}
+
+pub(crate) fn build(pattern: &str) -> Regex {
     RegexBuilder::new(pattern)
         .case_insensitive(true)
         .build()
@@ -439,6 +780,90 @@ fn build_set(patterns: &[&str]) -> RegexSet {
         .unwrap()
 }
 
+// A `\w`-class byte for the purposes of `\b`: letters, digits, and
+// underscore. WORD_PATTERN only ever *matches* letters, but `\b` still
+// treats digits and underscores as word characters, so a letter run
+// glued to a digit or underscore (`page2`, `COVID19`) has no boundary
+// between them and can't match at all.
+fn is_ascii_word_byte(b: u8) -> bool {
+    b.is_ascii_alphanumeric() || b == b'_'
+}
+
+// True if a `\b` falls between `text[pos - 1]` and `text[pos]`, i.e.
+// word-class-ness differs across that position. Out-of-range bytes
+// (before the start, at/past the end) count as non-word.
+fn ascii_word_boundary(text: &[u8], pos: usize) -> bool {
+    let before = pos.checked_sub(1).is_some_and(|i| is_ascii_word_byte(text[i]));
+    let after = text.get(pos).is_some_and(|&b| is_ascii_word_byte(b));
+    before != after
+}
+
+// Fast path for `word_count` on ASCII text: scans for runs of ASCII
+// letters instead of running the Unicode-aware word regex. A run may
+// be joined to one more run by a single internal hyphen or apostrophe,
+// mirroring WORD_PATTERN's `(?:[-']\p{L}+)?` tail. Candidate runs are
+// only counted where `\b` actually holds, so a letter run fused to a
+// trailing digit or underscore (no separating non-word byte) is
+// correctly skipped rather than counted as a word.
+fn ascii_word_count(text: &[u8]) -> usize {
+    let len = text.len();
+    let mut count = 0;
+    let mut i = 0;
+
+    while i < len {
+        if !text[i].is_ascii_alphabetic() || !ascii_word_boundary(text, i) {
+            i += 1;
+            continue;
+        }
+
+        let mut j = i;
+        while j < len && text[j].is_ascii_alphabetic() {
+            j += 1;
+        }
+
+        if matches!(text.get(j), Some(b'-') | Some(b'\''))
+            && text.get(j + 1).is_some_and(|b| b.is_ascii_alphabetic())
+        {
+            let mut k = j + 1;
+            while k < len && text[k].is_ascii_alphabetic() {
+                k += 1;
+            }
+
+            if ascii_word_boundary(text, k) {
+                count += 1;
+                i = k;
+                continue;
+            }
+        }
+
+        if ascii_word_boundary(text, j) {
+            count += 1;
+            i = j;
+        } else {
+            i += 1;
+        }
+    }
+
+    count
+}
+
+// Fast path for `sentence_count` on ASCII text: finds runs of `.`,
+// `?`, and `!` via `memchr3_iter` instead of running the sentence
+// regex, mirroring SENTENCE_PATTERN's `[.?!]+`.
+fn ascii_sentence_count(text: &[u8]) -> usize {
+    let mut count = 0;
+    let mut run_end = None;
+
+    for pos in memchr3_iter(b'.', b'?', b'!', text) {
+        if run_end != Some(pos) {
+            count += 1;
+        }
+        run_end = Some(pos + 1);
+    }
+
+    count
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -477,4 +902,81 @@ mod test {
             12
         );
     }
+
+    #[test]
+    fn test_formulas() {
+        let kincaid = Kincaid::new();
+        let mut scorer = kincaid.scorer();
+        scorer.add("The quick brown fox jumps over the lazy dog. It runs away quickly.");
+
+        assert!(scorer.gunning_fog().0 > 0.0);
+        assert!(scorer.smog().0 > 0.0);
+        assert!(scorer.automated_readability_index().0 > 0.0);
+        assert!(scorer.coleman_liau().0 > 0.0);
+    }
+
+    #[cfg(feature = "dale-chall")]
+    #[test]
+    fn test_dale_chall() {
+        let kincaid = Kincaid::new();
+        let mut scorer = kincaid.scorer();
+        scorer.add(
+            "The quick brown fox jumps over the lazy dog. It runs away quickly \
+             into the deep forest where it hides among tall trees and thick bushes.",
+        );
+
+        // This text reads at roughly a 4th-5th grade level by the other
+        // formulas; the built-in familiar-word list should keep
+        // dale_chall() in that same ballpark rather than flagging
+        // ordinary words like "fox" and "forest" as difficult.
+        let grade = scorer.dale_chall().unwrap().0;
+        assert!(grade > 0.0 && grade < 8.0, "unexpected dale-chall grade: {grade}");
+    }
+
+    #[test]
+    fn test_ascii_fast_path_agrees_with_regex() {
+        let kincaid = Kincaid::new();
+        let corpus = [
+            "",
+            "sample text",
+            "$5 only",
+            "This is noted in the book(1)",
+            "Hello, World! This is a test",
+            "Multiple...dots?!and marks!!!",
+            "One sentence. Another sentence. A third?",
+            "Test-case and co-op and a 'quoted' word.",
+            "19th century",
+            "page2",
+            "1990s",
+            "in the 1990s",
+            "COVID19",
+            "co-op2",
+        ];
+
+        for text in corpus {
+            assert_eq!(
+                ascii_word_count(text.as_bytes()),
+                kincaid.word.find_iter(text).count(),
+                "word count mismatch for {text:?}"
+            );
+            assert_eq!(
+                ascii_sentence_count(text.as_bytes()),
+                kincaid.sentence.find_iter(text).count(),
+                "sentence count mismatch for {text:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_lexical_diversity() {
+        let kincaid = Kincaid::new();
+        let mut scorer = kincaid.scorer();
+        scorer.add("The walker walks. The walker is walking.");
+
+        // "walks"/"walking" collapse to a shared stem, and "the"/"is"
+        // are excluded as stopwords, leaving "walker" and "walk".
+        assert_eq!(scorer.unique_word_count(), 2);
+        assert!(scorer.lexical_diversity() > 0.0);
+        assert!(scorer.lexical_diversity() < 1.0);
+    }
 }
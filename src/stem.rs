@@ -0,0 +1,65 @@
+//! A lightweight Porter-style suffix-stripping stemmer for English.
+//!
+//! This is not a full implementation of Porter's algorithm, just the
+//! common suffix rules that do most of the work of collapsing
+//! inflected forms onto a shared stem.
+
+#[cfg(feature = "std")]
+use std::string::String;
+#[cfg(not(feature = "std"))]
+use alloc::string::String;
+
+static DERIVATIONAL_SUFFIXES: &[&str] = &[
+    "ational", "tional", "ization", "ation", "ator", "alism", "iveness", "fulness", "ousness",
+    "aliti", "iviti", "biliti",
+];
+
+static INFLECTIONAL_SUFFIXES: &[&str] = &["ing", "edly", "ed", "ies", "es", "s"];
+
+static OTHER_SUFFIXES: &[&str] = &["ly", "ful", "ness", "ive", "able", "ible", "ment"];
+
+pub(crate) fn stem(word: &str) -> String {
+    let mut stem = word.to_lowercase();
+
+    for suffixes in [DERIVATIONAL_SUFFIXES, INFLECTIONAL_SUFFIXES, OTHER_SUFFIXES] {
+        if let Some(stripped) = strip_first_matching_suffix(&stem, suffixes) {
+            stem = stripped;
+        }
+    }
+
+    stem
+}
+
+fn strip_first_matching_suffix(word: &str, suffixes: &[&str]) -> Option<String> {
+    suffixes.iter().find_map(|suffix| strip_suffix(word, suffix))
+}
+
+// Strip `suffix` from `word` so long as at least three characters of
+// stem remain, to avoid reducing short words to nothing.
+fn strip_suffix(word: &str, suffix: &str) -> Option<String> {
+    let stripped = word.strip_suffix(suffix)?;
+
+    if stripped.chars().count() >= 3 {
+        Some(String::from(stripped))
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_stem_collapses_inflected_forms() {
+        assert_eq!(stem("walking"), stem("walks"));
+        assert_eq!(stem("jumped"), stem("jumping"));
+        assert_eq!(stem("national"), stem("nationalization"));
+    }
+
+    #[test]
+    fn test_stem_leaves_short_words_alone() {
+        assert_eq!(stem("as"), "as");
+        assert_eq!(stem("is"), "is");
+    }
+}
@@ -0,0 +1,149 @@
+//! Prose-style linting for weasel words, passive voice, and duplicated
+//! words.
+
+#[cfg(feature = "std")]
+use std::{ops::Range, string::String, string::ToString, vec::Vec};
+#[cfg(not(feature = "std"))]
+use core::ops::Range;
+#[cfg(not(feature = "std"))]
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use regex::Regex;
+
+use crate::build;
+
+static WEASEL_PATTERN: &str = r"\b(many|various|very|fairly|several|extremely|exceedingly|quite|remarkably|few|surprisingly|mostly|largely|huge|tiny|excellent|interestingly)\b";
+static PASSIVE_PATTERN: &str = r"\b(am|are|were|being|is|been|was|be)\b[\s\w]*?\b\w+ed\b";
+static WORD_PATTERN: &str = r"\b\w+\b";
+
+/// The kind of construction a [`Lint`] flags.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LintCategory {
+    Weasel,
+    Passive,
+    Duplicate,
+}
+
+/// A single flagged span of text.
+#[derive(Clone, Debug)]
+pub struct Lint {
+    pub category: LintCategory,
+    pub span: Range<usize>,
+    pub matched_text: String,
+}
+
+/// Flags weasel words, passive voice, and duplicated words in prose.
+#[derive(Clone, Debug)]
+pub struct StyleLinter {
+    weasel: Regex,
+    passive: Regex,
+    word: Regex,
+}
+
+impl StyleLinter {
+    pub fn new() -> Self {
+        Self {
+            weasel: build(WEASEL_PATTERN),
+            passive: build(PASSIVE_PATTERN),
+            word: build(WORD_PATTERN),
+        }
+    }
+
+    /// Lint `text`, returning every flagged span in order of appearance.
+    pub fn lint(&self, text: &str) -> Vec<Lint> {
+        let mut lints: Vec<_> = self
+            .weasel
+            .find_iter(text)
+            .map(|m| Lint::new(LintCategory::Weasel, m))
+            .chain(
+                self.passive
+                    .find_iter(text)
+                    .map(|m| Lint::new(LintCategory::Passive, m)),
+            )
+            .chain(self.duplicate_lints(text))
+            .collect();
+
+        lints.sort_by_key(|lint| lint.span.start);
+        lints
+    }
+
+    // The `regex` crate doesn't support backreferences, so adjacent
+    // repeated words are found by walking word matches pairwise instead
+    // of matching `\b(\w+)\s+\1\b` directly.
+    fn duplicate_lints(&self, text: &str) -> Vec<Lint> {
+        let mut lints = Vec::new();
+        let mut prev: Option<regex::Match> = None;
+
+        for m in self.word.find_iter(text) {
+            if let Some(prev_match) = prev {
+                let gap = &text[prev_match.end()..m.start()];
+                if gap.chars().all(char::is_whitespace)
+                    && prev_match.as_str().eq_ignore_ascii_case(m.as_str())
+                {
+                    lints.push(Lint {
+                        category: LintCategory::Duplicate,
+                        span: prev_match.start()..m.end(),
+                        matched_text: text[prev_match.start()..m.end()].to_string(),
+                    });
+                }
+            }
+            prev = Some(m);
+        }
+
+        lints
+    }
+}
+
+impl Default for StyleLinter {
+    fn default() -> Self {
+        StyleLinter::new()
+    }
+}
+
+impl Lint {
+    fn new(category: LintCategory, m: regex::Match) -> Self {
+        Self {
+            category,
+            span: m.start()..m.end(),
+            matched_text: m.as_str().to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_lint_weasel() {
+        let linter = StyleLinter::new();
+        let lints = linter.lint("This is a very exciting and largely untested idea.");
+
+        assert!(lints
+            .iter()
+            .any(|lint| lint.category == LintCategory::Weasel && lint.matched_text == "very"));
+    }
+
+    #[test]
+    fn test_lint_passive() {
+        let linter = StyleLinter::new();
+        let lints = linter.lint("The ball was kicked by the pitcher.");
+
+        assert!(lints
+            .iter()
+            .any(|lint| lint.category == LintCategory::Passive));
+    }
+
+    #[test]
+    fn test_lint_duplicate() {
+        let linter = StyleLinter::new();
+        let lints = linter.lint("This is is a mistake.");
+
+        assert!(lints
+            .iter()
+            .any(|lint| lint.category == LintCategory::Duplicate && lint.matched_text == "is is"));
+    }
+}